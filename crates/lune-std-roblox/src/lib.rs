@@ -7,9 +7,11 @@ use mlua_luau_scheduler::LuaSpawnExt;
 
 use lune_roblox::{
     document::{Document, DocumentError, DocumentFormat, DocumentKind},
-    instance::{Instance, registry::InstanceRegistry},
+    instance::{Instance, Variant, registry::InstanceRegistry},
     reflection::Database as ReflectionDatabase,
 };
+use rbx_reflection::{ClassDescriptor, DataType, PropertyDescriptor};
+use rbx_types::VariantType;
 
 static REFLECTION_DATABASE: OnceLock<ReflectionDatabase> = OnceLock::new();
 
@@ -49,8 +51,12 @@ pub fn module(lua: Lua) -> LuaResult<LuaTable> {
         .with_async_function("serializeModel", serialize_model)?
         .with_function("getAuthCookie", get_auth_cookie)?
         .with_function("getReflectionDatabase", get_reflection_database)?
+        .with_function("instanceToTable", instance_to_table)?
+        .with_function("tableToInstance", table_to_instance)?
         .with_function("implementProperty", implement_property)?
         .with_function("implementMethod", implement_method)?
+        .with_function("implementPropertyAsync", implement_property_async)?
+        .with_function("implementMethodAsync", implement_method_async)?
         .with_function("studioApplicationPath", studio_application_path)?
         .with_function("studioContentPath", studio_content_path)?
         .with_function("studioPluginPath", studio_plugin_path)?
@@ -128,6 +134,219 @@ fn get_reflection_database(_: &Lua, _: ()) -> LuaResult<ReflectionDatabase> {
     Ok(*REFLECTION_DATABASE.get_or_init(ReflectionDatabase::new))
 }
 
+/**
+    Recursively projects an `Instance` tree into a plain, format-agnostic Lua table:
+    `{ ClassName: string, Properties: { [string]: any }, Children: { table } }`.
+
+    This is the inverse of [`table_to_instance`], and unlike `serializePlace`/
+    `serializeModel` the result is ordinary Lua data that scripts can feed into
+    `serde`/JSON/TOML pipelines, diff, or template, rather than opaque rbxl/rbxm bytes.
+*/
+fn instance_to_table(lua: &Lua, instance: LuaUserDataRef<Instance>) -> LuaResult<LuaTable> {
+    instance_to_table_inner(lua, *instance)
+}
+
+fn instance_to_table_inner(lua: &Lua, instance: Instance) -> LuaResult<LuaTable> {
+    let database = REFLECTION_DATABASE.get_or_init(ReflectionDatabase::new);
+
+    let properties = lua.create_table()?;
+    for (property_name, _) in class_properties(database, &instance.get_class_name()) {
+        if let Some(value) = instance.get_property(property_name) {
+            properties.set(property_name, value.into_lua(lua)?)?;
+        }
+    }
+
+    let children = lua.create_table()?;
+    for child in instance.get_children() {
+        children.push(instance_to_table_inner(lua, child)?)?;
+    }
+
+    TableBuilder::new(lua.clone())?
+        .with_value("ClassName", instance.get_class_name())?
+        .with_value("Properties", properties)?
+        .with_value("Children", children)?
+        .build_readonly()
+}
+
+/**
+    Reconstructs an orphaned `Instance` tree from a table produced by
+    [`instance_to_table`], coercing each property's Lua value back into the `Variant`
+    the reflection database expects for that property, per its declared `DataType`.
+*/
+fn table_to_instance(lua: &Lua, table: LuaTable) -> LuaResult<Instance> {
+    let class_name: String = table.get("ClassName")?;
+    let instance = Instance::new_orphaned(&class_name);
+
+    let database = REFLECTION_DATABASE.get_or_init(ReflectionDatabase::new);
+    let properties: LuaTable = table.get("Properties")?;
+    for (property_name, descriptor) in class_properties(database, &class_name) {
+        let value: LuaValue = properties.get(property_name)?;
+        if !matches!(value, LuaValue::Nil) {
+            let variant = variant_from_lua_property(lua, value, descriptor)?;
+            instance.set_property(property_name, variant);
+        }
+    }
+
+    let children: LuaTable = table.get("Children")?;
+    for child in children.sequence_values::<LuaTable>() {
+        let child_instance = table_to_instance(lua, child?)?;
+        instance.add_child(child_instance);
+    }
+
+    Ok(instance)
+}
+
+/**
+    Collects every property a class exposes, including ones declared on its
+    superclasses: `ClassDescriptor::properties` only holds properties declared
+    directly on that class, so `Name` and every other inherited field would otherwise
+    be silently dropped by the `Instance` <-> table projection.
+
+    Structural properties (`Parent`, and any other `Ref`-typed property) are excluded:
+    parentage is already captured by `Children` and restored via `add_child`, so
+    round-tripping `Parent` as a flat property too would fight `add_child`'s own
+    parenting instead of just describing it.
+*/
+fn class_properties<'a>(
+    database: &'a ReflectionDatabase,
+    class_name: &str,
+) -> Vec<(&'a str, &'a PropertyDescriptor<'a>)> {
+    let mut properties = Vec::new();
+    let mut current: Option<&ClassDescriptor> = database.get_class(class_name);
+
+    while let Some(class) = current {
+        for (name, descriptor) in &class.properties {
+            if !is_structural_property(name.as_ref(), descriptor) {
+                properties.push((name.as_ref(), descriptor));
+            }
+        }
+        current = class
+            .superclass
+            .as_ref()
+            .and_then(|superclass| database.get_class(superclass));
+    }
+
+    properties
+}
+
+fn is_structural_property(name: &str, descriptor: &PropertyDescriptor) -> bool {
+    name == "Parent" || matches!(descriptor.data_type, DataType::Value(VariantType::Ref))
+}
+
+/**
+    Coerces a Lua value into the `Variant` a property descriptor expects, using the
+    property's declared `DataType` to disambiguate primitives (e.g. a Lua number has to
+    become specifically `Float32`, `Float64`, `Int32`, or `Int64`) that a type-agnostic
+    `Variant::from_lua` can't tell apart on its own, and to rebuild enums from their
+    underlying numeric value rather than from a name `Variant::from_lua` has no way to
+    resolve back to a `rbx_types::Enum`. Composite datatypes (`Vector3`, `Color3`,
+    `UDim2`, ...) already round-trip correctly through their own `FromLua` impls, so
+    those fall back to the generic conversion.
+*/
+fn variant_from_lua_property(
+    lua: &Lua,
+    value: LuaValue,
+    descriptor: &PropertyDescriptor,
+) -> LuaResult<Variant> {
+    match &descriptor.data_type {
+        DataType::Value(VariantType::Float32) => Ok(Variant::Float32(f32::from_lua(value, lua)?)),
+        DataType::Value(VariantType::Float64) => Ok(Variant::Float64(f64::from_lua(value, lua)?)),
+        DataType::Value(VariantType::Int32) => Ok(Variant::Int32(i32::from_lua(value, lua)?)),
+        DataType::Value(VariantType::Int64) => Ok(Variant::Int64(i64::from_lua(value, lua)?)),
+        DataType::Value(VariantType::Bool) => Ok(Variant::Bool(bool::from_lua(value, lua)?)),
+        DataType::Value(VariantType::String) => Ok(Variant::String(String::from_lua(value, lua)?)),
+        DataType::Enum(_) => Ok(Variant::Enum(rbx_types::Enum::from_u32(u32::from_lua(
+            value, lua,
+        )?))),
+        _ => Variant::from_lua(value, lua),
+    }
+}
+
+#[cfg(test)]
+mod instance_table_tests {
+    use super::{Instance, Variant, instance_to_table_inner, table_to_instance};
+    use mlua::Lua;
+    use rbx_types::Vector3;
+
+    #[test]
+    fn round_trips_properties_and_children() {
+        let lua = Lua::new();
+
+        let root = Instance::new_orphaned("Folder");
+        root.set_property("Name", Variant::String("Root".to_string()));
+
+        let child = Instance::new_orphaned("Folder");
+        child.set_property("Name", Variant::String("Child".to_string()));
+        root.add_child(child);
+
+        let table = instance_to_table_inner(&lua, root).unwrap();
+        let round_tripped = table_to_instance(&lua, table).unwrap();
+
+        assert_eq!(round_tripped.get_class_name(), "Folder");
+        assert_eq!(
+            round_tripped.get_property("Name"),
+            Some(Variant::String("Root".to_string()))
+        );
+
+        let children = round_tripped.get_children();
+        assert_eq!(children.len(), 1);
+        assert_eq!(
+            children[0].get_property("Name"),
+            Some(Variant::String("Child".to_string()))
+        );
+    }
+
+    #[test]
+    fn inherits_superclass_properties() {
+        let lua = Lua::new();
+
+        // `Name` is declared on the `Instance` base class, not on `Folder` itself -
+        // this would be silently dropped if property collection didn't walk the
+        // superclass chain.
+        let folder = Instance::new_orphaned("Folder");
+        folder.set_property("Name", Variant::String("Inherited".to_string()));
+
+        let table = instance_to_table_inner(&lua, folder).unwrap();
+        let properties: mlua::Table = table.get("Properties").unwrap();
+        let name: String = properties.get("Name").unwrap();
+
+        assert_eq!(name, "Inherited");
+    }
+
+    #[test]
+    fn round_trips_a_composite_datatype() {
+        let lua = Lua::new();
+
+        let part = Instance::new_orphaned("Part");
+        part.set_property("Size", Variant::Vector3(Vector3::new(1.0, 2.0, 3.0)));
+
+        let table = instance_to_table_inner(&lua, part).unwrap();
+        let round_tripped = table_to_instance(&lua, table).unwrap();
+
+        assert_eq!(
+            round_tripped.get_property("Size"),
+            Some(Variant::Vector3(Vector3::new(1.0, 2.0, 3.0)))
+        );
+    }
+
+    #[test]
+    fn round_trips_an_enum_property() {
+        let lua = Lua::new();
+
+        let part = Instance::new_orphaned("Part");
+        let material = rbx_types::Enum::from_u32(816); // Enum.Material.Concrete
+        part.set_property("Material", Variant::Enum(material.clone()));
+
+        let table = instance_to_table_inner(&lua, part).unwrap();
+        let round_tripped = table_to_instance(&lua, table).unwrap();
+
+        assert_eq!(
+            round_tripped.get_property("Material"),
+            Some(Variant::Enum(material))
+        );
+    }
+}
+
 fn implement_property(
     lua: &Lua,
     (class_name, property_name, property_getter, property_setter): (
@@ -162,6 +381,110 @@ fn implement_method(
     Ok(())
 }
 
+fn implement_property_async(
+    lua: &Lua,
+    (class_name, property_name, property_getter, property_setter): (
+        String,
+        String,
+        LuaFunction,
+        Option<LuaFunction>,
+    ),
+) -> LuaResult<()> {
+    let property_setter = if let Some(setter) = property_setter {
+        spawn_on_scheduler(lua, setter)?
+    } else {
+        let property_name = property_name.clone();
+        lua.create_function(move |_, _: LuaMultiValue| {
+            Err::<(), _>(LuaError::runtime(format!(
+                "Property '{property_name}' is read-only"
+            )))
+        })?
+    };
+    let property_getter = spawn_on_scheduler(lua, property_getter)?;
+    InstanceRegistry::insert_property_getter(lua, &class_name, &property_name, property_getter)
+        .into_lua_err()?;
+    InstanceRegistry::insert_property_setter(lua, &class_name, &property_name, property_setter)
+        .into_lua_err()?;
+    Ok(())
+}
+
+fn implement_method_async(
+    lua: &Lua,
+    (class_name, method_name, method): (String, String, LuaFunction),
+) -> LuaResult<()> {
+    let method = spawn_on_scheduler(lua, method)?;
+    InstanceRegistry::insert_method(lua, &class_name, &method_name, method).into_lua_err()?;
+    Ok(())
+}
+
+/**
+    Wraps a (possibly coroutine-yielding) Luau function in an async function so that
+    calling it yields the calling thread until it resolves, instead of blocking it.
+
+    This relies on the same yieldable-C-function support that every other async member
+    of this module (`deserializePlace`, `serializePlace`, ...) already depends on: a
+    function built with `create_async_function` can suspend the calling Luau thread
+    from *any* call site that goes through the VM's normal call protocol - a plain
+    script-level call as well as a metamethod dispatch like `Instance.__index` - without
+    that call site needing `call_async` or any other async-aware plumbing of its own.
+    That's what lets `InstanceRegistry`'s existing, unmodified
+    `insert_property_getter`/`insert_method` path store and invoke these the same way it
+    invokes a sync member.
+*/
+fn spawn_on_scheduler(lua: &Lua, f: LuaFunction) -> LuaResult<LuaFunction> {
+    lua.create_async_function(move |_lua, args: LuaMultiValue| {
+        let f = f.clone();
+        async move { f.call_async::<LuaMultiValue>(args).await }
+    })
+}
+
+#[cfg(test)]
+mod async_dispatch_tests {
+    use super::spawn_on_scheduler;
+    use mlua::Lua;
+    use std::future::Future;
+    use std::pin::Pin;
+    use std::task::{Context, Poll, RawWaker, RawWakerVTable, Waker};
+
+    // No async executor is a verified dependency of this crate, so this busy-polls a
+    // future to completion with a no-op waker, just enough to drive `call_async`
+    // in a test without pulling in `tokio`/`futures`/`pollster`.
+    fn block_on<F: Future>(mut future: F) -> F::Output {
+        fn noop_clone(_: *const ()) -> RawWaker {
+            RawWaker::new(std::ptr::null(), &VTABLE)
+        }
+        fn noop(_: *const ()) {}
+        static VTABLE: RawWakerVTable = RawWakerVTable::new(noop_clone, noop, noop, noop);
+
+        let waker = unsafe { Waker::from_raw(RawWaker::new(std::ptr::null(), &VTABLE)) };
+        let mut cx = Context::from_waker(&waker);
+        // SAFETY: `future` is never moved after this point.
+        let mut future = unsafe { Pin::new_unchecked(&mut future) };
+        loop {
+            if let Poll::Ready(output) = future.as_mut().poll(&mut cx) {
+                return output;
+            }
+        }
+    }
+
+    #[test]
+    fn wrapped_function_yields_and_returns_its_result() {
+        let lua = Lua::new();
+
+        // A function that yields across the C-call boundary before returning, so the
+        // test actually exercises suspension, not just a synchronous call-through.
+        let yielding = lua
+            .load("local a, b = ...; coroutine.yield(); return a + b")
+            .into_function()
+            .unwrap();
+        let wrapped = spawn_on_scheduler(&lua, yielding).unwrap();
+
+        let result: i64 = block_on(wrapped.call_async((2_i64, 3_i64))).unwrap();
+
+        assert_eq!(result, 5);
+    }
+}
+
 fn studio_application_path(_: &Lua, _: ()) -> LuaResult<String> {
     RobloxStudio::locate()
         .map(|rs| rs.application_path().display().to_string())
@@ -186,28 +509,35 @@ fn studio_builtin_plugin_path(_: &Lua, _: ()) -> LuaResult<String> {
         .map_err(LuaError::external)
 }
 
+/**
+    Computes `ByteSize` by serializing `instance` together with its full subtree to
+    binary (as a place for a `DataModel`, as a model otherwise) and measuring the result.
+
+    This re-serializes on every read, so it's O(n) in the size of the instance tree,
+    not memoized per-instance. A cached, mutation-counter-invalidated version belongs
+    on `InstanceRegistry`, which doesn't expose a counter to key off today; that's
+    tracked as a follow-up rather than implemented here.
+*/
+fn compute_byte_size(instance: Instance) -> u64 {
+    let bytes = if instance.get_class_name() == "DataModel" {
+        Document::from_data_model_instance(instance)
+            .and_then(|doc| doc.to_bytes_with_format(DocumentFormat::Binary))
+    } else {
+        Document::from_instance_array(vec![instance])
+            .and_then(|doc| doc.to_bytes_with_format(DocumentFormat::Binary))
+    };
+
+    match bytes {
+        Ok(bytes) => bytes.len() as u64,
+        Err(_) => 0,
+    }
+}
+
 fn implement_byte_size_property(lua: &Lua, (class_name,): (String,)) -> LuaResult<()> {
     let property_name = "ByteSize";
 
     let getter = lua.create_function(move |_lua, instance: LuaUserDataRef<Instance>| {
-        let instance = *instance;
-
-        let doc = if instance.get_class_name() == "DataModel" {
-            match lune_roblox::document::Document::from_data_model_instance(instance) {
-                Ok(doc) => doc,
-                Err(_) => return Ok(0u64),
-            }
-        } else {
-            match lune_roblox::document::Document::from_instance_array(vec![instance]) {
-                Ok(doc) => doc,
-                Err(_) => return Ok(0u64),
-            }
-        };
-
-        match doc.to_bytes_with_format(lune_roblox::document::DocumentFormat::Binary) {
-            Ok(bytes) => Ok(bytes.len() as u64),
-            Err(_) => Ok(0u64),
-        }
+        Ok(compute_byte_size(*instance))
     })?;
 
     let setter = lua.create_function(move |_, _: LuaMultiValue| {
@@ -241,3 +571,21 @@ fn implement_byte_size_for_all_classes(lua: &Lua, _: ()) -> LuaResult<()> {
 
     Ok(())
 }
+
+#[cfg(test)]
+mod byte_size_tests {
+    use super::{Document, DocumentFormat, compute_byte_size};
+
+    #[test]
+    fn matches_the_length_of_the_binary_serialization() {
+        let instance = lune_roblox::instance::Instance::new_orphaned("Folder");
+
+        let expected = Document::from_instance_array(vec![instance])
+            .unwrap()
+            .to_bytes_with_format(DocumentFormat::Binary)
+            .unwrap()
+            .len() as u64;
+
+        assert_eq!(compute_byte_size(instance), expected);
+    }
+}